@@ -36,9 +36,67 @@
 //!       excludes:
 //!         - twitter
 //! ```
+//!
+//! Set `keywords.regex: true` to treat every `includes`/`excludes` entry as a
+//! regular expression (compiled once in [`Filter::init`]) instead of a plain
+//! substring.
+//!
+//! Add `actions` to a filter to run something when it matches, instead of
+//! having the consumer poll and re-dispatch:
+//!
+//! ```yaml
+//!     actions:
+//!       - type: webhook
+//!         url: https://example.com/hook
+//!       - type: log
+//!         level: info
+//! ```
+//!
+//! Add `records` to match on fields of the record itself rather than the
+//! post text:
+//!
+//! ```yaml
+//!     records:
+//!       collections:
+//!         - app.bsky.feed.post
+//!       langs:
+//!         - en
+//!       has_media: true
+//! ```
+//!
+//! A top-level `bans` list drops events from the given DIDs/handles before
+//! any filter below is evaluated:
+//!
+//! ```yaml
+//! bans:
+//!   dids:
+//!     - did:plc:abusiveaccount000000000000
+//!   handles:
+//!     - muted.bsky.social
+//! filters:
+//!   - name: bluesky team
+//! ```
+//!
+//! By default a filter matches if any of its configured `subscribes`/
+//! `keywords`/`records` conditions match; a `keywords.excludes` hit always
+//! rejects the post, whatever else matches. Set `match_mode` to compose
+//! conditions explicitly with `all`/`any`/`not`:
+//!
+//! ```yaml
+//!     match_mode:
+//!       type: all
+//!       value:
+//!         - type: subscribes
+//!         - type: keywords
+//! ```
+//!
+//! `Filters::init` validates every filter (degenerate conditions, handles
+//! that failed to resolve, uncompilable regex) and returns an error instead
+//! of silently starting with a misconfigured filter.
 use std::collections::HashSet;
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::{Client, Commit, Event, Handle, Payload};
@@ -118,16 +176,150 @@ impl Subscribes {
   }
 }
 
+/// Global DID/handle blocklist, applied before any filter is evaluated
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct Bans {
+  pub dids: Option<Vec<String>>,
+  pub handles: Option<Vec<String>>,
+  /// Handles that failed to resolve to a DID during `init`
+  #[serde(skip)]
+  unresolved_handles: Vec<String>,
+}
+
+impl Bans {
+  /// Convert the banned handles to DIDs, same as `Filter::init` does for `subscribes`
+  pub fn init(&mut self, client: &mut Client) {
+    let Some(handles) = &self.handles else {
+      return;
+    };
+    let (converted, unresolved): (Vec<_>, Vec<_>) = handles
+      .iter()
+      .map(|h| (h, client.get_handle(h)))
+      .partition(|(_, r)| r.is_ok());
+    self.unresolved_handles = unresolved.into_iter().map(|(h, _)| h.clone()).collect();
+    let converted = converted
+      .into_iter()
+      .filter_map(|(_, r)| r.ok())
+      .collect::<HashSet<_>>();
+    let dids = self
+      .dids
+      .clone()
+      .map(|d| d.into_iter().collect::<HashSet<_>>())
+      .unwrap_or_default();
+    let dids = dids.union(&converted).cloned().collect::<Vec<_>>();
+    if dids.is_empty() {
+      self.dids = None;
+    } else {
+      self.dids = Some(dids);
+    }
+  }
+
+  /// Check for handles that failed to resolve to a DID during `init`
+  pub fn validate(&self) -> Result<()> {
+    if !self.unresolved_handles.is_empty() {
+      bail!(
+        "bans has handles that failed to resolve to a DID: {:?}",
+        self.unresolved_handles
+      );
+    }
+    Ok(())
+  }
+
+  /// Returns whether the given DID is banned
+  pub fn is_banned(&self, did: &str) -> bool {
+    match &self.dids {
+      Some(dids) => dids.iter().any(|d| d == did),
+      None => false,
+    }
+  }
+
+  /// Add a DID to the blocklist
+  pub fn ban_did<T: ToString>(&mut self, did: T) {
+    match self.dids.as_mut() {
+      Some(dids) => dids.push(did.to_string()),
+      None => self.dids = Some(vec![did.to_string()]),
+    }
+  }
+
+  /// Remove a DID from the blocklist
+  pub fn unban_did<T: ToString>(&mut self, did: T) -> Result<()> {
+    let did = did.to_string();
+    match self.dids.as_ref() {
+      Some(dids) => {
+        self.dids = Some(dids.iter().filter(|d| **d != did).cloned().collect());
+        Ok(())
+      }
+      None => bail!("no such did"),
+    }
+  }
+
+  /// Add a handle to the blocklist
+  pub fn ban_handle<T: ToString>(&mut self, handle: T) {
+    match self.handles.as_mut() {
+      Some(handles) => handles.push(handle.to_string()),
+      None => self.handles = Some(vec![handle.to_string()]),
+    }
+  }
+
+  /// Remove a handle from the blocklist
+  pub fn unban_handle<T: ToString>(&mut self, handle: T) -> Result<()> {
+    let handle = handle.to_string();
+    match self.handles.as_ref() {
+      Some(handles) => {
+        self.handles = Some(handles.iter().filter(|h| **h != handle).cloned().collect());
+        Ok(())
+      }
+      None => bail!("no such handle"),
+    }
+  }
+}
+
 /// Filter by Keyword
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Keywords {
   pub includes: Option<Vec<String>>,
   pub excludes: Option<Vec<String>>,
+  /// When true, treat every `includes`/`excludes` entry as a regular
+  /// expression instead of a plain substring.
+  pub regex: Option<bool>,
+  #[serde(skip)]
+  compiled_includes: Vec<Regex>,
+  #[serde(skip)]
+  compiled_excludes: Vec<Regex>,
 }
 
 impl Keywords {
+  /// Compile `includes`/`excludes` patterns once, ahead of the hot path.
+  ///
+  /// Called from `Filter::init` so every firehose commit is matched
+  /// against already-compiled patterns instead of recompiling them.
+  pub fn init(&mut self) -> Result<()> {
+    if !self.regex.unwrap_or(false) {
+      return Ok(());
+    }
+    self.compiled_includes = Self::compile(&self.includes)?;
+    self.compiled_excludes = Self::compile(&self.excludes)?;
+    Ok(())
+  }
+
+  fn compile(patterns: &Option<Vec<String>>) -> Result<Vec<Regex>> {
+    let Some(patterns) = patterns else {
+      return Ok(Vec::new());
+    };
+    patterns
+      .iter()
+      .map(|p| Regex::new(p).map_err(|e| anyhow!("invalid regex {p:?}: {e}")))
+      .collect()
+  }
+
   /// Returns whether the specified string is included in the Event received from all repositories
   pub fn includes(&self, commit: &Commit) -> bool {
+    if self.regex.unwrap_or(false) {
+      return commit
+        .get_post_text()
+        .iter()
+        .any(|p| self.compiled_includes.iter().any(|re| re.is_match(p)));
+    }
     let Some(includes) = &self.includes else {
       return false;
     };
@@ -139,6 +331,12 @@ impl Keywords {
 
   /// Returns whether the specified string is included in the Event received from the subscribed repository.
   pub fn excludes(&self, commit: &Commit) -> bool {
+    if self.regex.unwrap_or(false) {
+      return commit
+        .get_post_text()
+        .iter()
+        .any(|p| self.compiled_excludes.iter().any(|re| re.is_match(p)));
+    }
     let Some(excludes) = &self.excludes else {
       return false;
     };
@@ -149,39 +347,309 @@ impl Keywords {
   }
 }
 
+/// An action run against a matched [`Event`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Action {
+  /// Send the matched event as JSON to a URL
+  Webhook {
+    url: String,
+    /// HTTP method to use, defaults to `POST`
+    method: Option<String>,
+  },
+  /// Run a program, writing the matched event as JSON to its stdin
+  RunCommand { program: String, args: Vec<String> },
+  /// Append the matched event as a JSON line to a file
+  AppendJson { path: String },
+  /// Log the matched event at the given level (`error`, `warn`, `info`, `debug`, `trace`)
+  Log { level: String },
+}
+
+/// How long a single `Action` is allowed to run before it's treated as failed,
+/// so a slow/unreachable webhook or process can't block matching for every
+/// other filter on every firehose commit.
+const ACTION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+impl Action {
+  /// Run this action for a matched event
+  pub fn run(&self, event: &Event) -> Result<()> {
+    let json = serde_json::to_string(event)?;
+    match self {
+      Action::Webhook { url, method } => {
+        let method = method.as_deref().unwrap_or("POST");
+        let client = reqwest::blocking::Client::builder()
+          .timeout(ACTION_TIMEOUT)
+          .build()?;
+        client
+          .request(method.parse()?, url)
+          .header("content-type", "application/json")
+          .body(json)
+          .send()?;
+      }
+      Action::RunCommand { program, args } => {
+        use std::io::Write;
+        let mut child = std::process::Command::new(program)
+          .args(args)
+          .stdin(std::process::Stdio::piped())
+          .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+          stdin.write_all(json.as_bytes())?;
+        }
+        let deadline = std::time::Instant::now() + ACTION_TIMEOUT;
+        loop {
+          if let Some(status) = child.try_wait()? {
+            if !status.success() {
+              bail!("command {program} exited with {status}");
+            }
+            break;
+          }
+          if std::time::Instant::now() >= deadline {
+            child.kill()?;
+            bail!("command {program} timed out after {ACTION_TIMEOUT:?}");
+          }
+          std::thread::sleep(std::time::Duration::from_millis(50));
+        }
+      }
+      Action::AppendJson { path } => {
+        use std::io::Write;
+        let mut file = std::fs::OpenOptions::new()
+          .create(true)
+          .append(true)
+          .open(path)?;
+        writeln!(file, "{json}")?;
+      }
+      Action::Log { level } => match level.as_str() {
+        "error" => log::error!("{json}"),
+        "warn" => log::warn!("{json}"),
+        "info" => log::info!("{json}"),
+        "debug" => log::debug!("{json}"),
+        _ => log::trace!("{json}"),
+      },
+    }
+    Ok(())
+  }
+}
+
+/// Filter by record fields beyond the post text
+///
+/// Every sub-field is OR-within (any of the given values matches) and the
+/// sub-fields are AND-across (all configured sub-fields must match); an
+/// absent or empty sub-field is a wildcard.
+///
+/// `is_match` below depends on `Commit` exposing a `collection: String` field
+/// and `get_langs`, `has_media`, `is_reply`, `is_quote`, `get_mentions` and
+/// `get_hashtags` accessors alongside its existing `get_post_text`. This
+/// module doesn't define `Commit` itself (it lives in this crate's
+/// `lib.rs`/`commit.rs`), so those members must be added there for this
+/// file to compile; nothing here can add them.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RecordFilter {
+  /// NSIDs, e.g. `app.bsky.feed.post`, `app.bsky.feed.like`, `app.bsky.graph.follow`
+  pub collections: Option<Vec<String>>,
+  /// BCP-47 language codes from the post's `langs` field
+  pub langs: Option<Vec<String>>,
+  /// Require an image/video embed
+  pub has_media: Option<bool>,
+  /// Require the post to be a reply
+  pub is_reply: Option<bool>,
+  /// Require the post to be a quote post
+  pub is_quote: Option<bool>,
+  /// DIDs referenced in facet features
+  pub mentions: Option<Vec<String>>,
+  pub hashtags: Option<Vec<String>>,
+}
+
+impl RecordFilter {
+  /// Returns whether or not the record filter is matched
+  ///
+  /// A `None` or empty `Vec` sub-field is a wildcard; a non-empty `Vec` must
+  /// match at least one of its values (OR-within).
+  pub fn is_match(&self, commit: &Commit) -> bool {
+    if !Self::matches_any(&self.collections, |c| *c == commit.collection) {
+      return false;
+    }
+    if !Self::matches_any(&self.langs, |l| commit.get_langs().contains(l)) {
+      return false;
+    }
+    if let Some(has_media) = self.has_media {
+      if commit.has_media() != has_media {
+        return false;
+      }
+    }
+    if let Some(is_reply) = self.is_reply {
+      if commit.is_reply() != is_reply {
+        return false;
+      }
+    }
+    if let Some(is_quote) = self.is_quote {
+      if commit.is_quote() != is_quote {
+        return false;
+      }
+    }
+    if !Self::matches_any(&self.mentions, |m| commit.get_mentions().contains(m)) {
+      return false;
+    }
+    if !Self::matches_any(&self.hashtags, |h| commit.get_hashtags().contains(h)) {
+      return false;
+    }
+    true
+  }
+
+  /// A `None` or empty values list is a wildcard; otherwise at least one value must match
+  fn matches_any<T>(values: &Option<Vec<T>>, matches: impl Fn(&T) -> bool) -> bool {
+    values
+      .as_ref()
+      .map_or(true, |v| v.is_empty() || v.iter().any(matches))
+  }
+}
+
+/// Boolean composition of a [`Filter`]'s conditions
+///
+/// `Subscribes`, `Keywords` and `Records` refer to the matching filter's own
+/// `subscribes`/`keywords`/`records` fields: an absent `subscribes`/
+/// `keywords` never matches, while an absent `records` is a wildcard (same
+/// as `RecordFilter`'s own sub-fields).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Condition {
+  Subscribes,
+  Keywords,
+  Records,
+  All(Vec<Condition>),
+  Any(Vec<Condition>),
+  Not(Box<Condition>),
+}
+
+impl Condition {
+  /// Returns whether or not the condition is matched for the given filter and commit
+  pub fn is_match(&self, filter: &Filter, commit: &Commit) -> bool {
+    match self {
+      Condition::Subscribes => filter.is_follows_match(commit),
+      Condition::Keywords => filter.is_keywords_includes(commit),
+      Condition::Records => filter.is_records_match(commit),
+      Condition::All(conditions) => conditions.iter().all(|c| c.is_match(filter, commit)),
+      Condition::Any(conditions) => conditions.iter().any(|c| c.is_match(filter, commit)),
+      Condition::Not(condition) => !condition.is_match(filter, commit),
+    }
+  }
+}
+
 /// Filter
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Filter {
   pub name: String,
   pub subscribes: Option<Subscribes>,
   pub keywords: Option<Keywords>,
+  pub records: Option<RecordFilter>,
+  /// How `subscribes`, `keywords` and `records` combine; defaults to an
+  /// `Any` of whichever of those three are configured
+  pub match_mode: Option<Condition>,
+  pub actions: Option<Vec<Action>>,
+  /// Handles that failed to resolve to a DID during `init`
+  #[serde(skip)]
+  unresolved_handles: Vec<String>,
+  /// Set during `init` when the filter resolved to nothing usable, so
+  /// `is_match` fails closed instead of leaking unintended events
+  #[serde(skip)]
+  force_no_match: bool,
+  /// The owning [`Filters`]' resolved ban list, set by `Filters::init` so
+  /// `is_match`/`get_filters` short-circuit to "no match" for banned
+  /// repositories even when called directly, without going through `dispatch`
+  #[serde(skip)]
+  bans: Option<Bans>,
 }
 
 impl Filter {
-  /// Convert the Handle in the filter to a DID
-  pub fn init(&mut self, client: &mut Client) {
-    let Some(follows) = self.subscribes.as_mut() else {
-      return;
+  /// Convert the Handle in the filter to a DID, and compile any regex keywords
+  pub fn init(&mut self, client: &mut Client) -> Result<()> {
+    if let Some(follows) = self.subscribes.as_mut() {
+      if let Some(handles) = &follows.handles {
+        let (converted, unresolved): (Vec<_>, Vec<_>) = handles
+          .iter()
+          .map(|h| (h, client.get_handle(h)))
+          .partition(|(_, r)| r.is_ok());
+        self.unresolved_handles = unresolved.into_iter().map(|(h, _)| h.clone()).collect();
+        let converted = converted
+          .into_iter()
+          .filter_map(|(_, r)| r.ok())
+          .collect::<HashSet<_>>();
+        let dids = follows
+          .dids
+          .clone()
+          .map(|d| d.into_iter().collect::<HashSet<_>>())
+          .unwrap_or_default();
+        let dids = dids.union(&converted).cloned().collect::<Vec<_>>();
+        if dids.is_empty() {
+          follows.dids = None;
+        } else {
+          follows.dids = Some(dids);
+        }
+      }
+    }
+    if let Some(keywords) = self.keywords.as_mut() {
+      keywords.init()?;
+    }
+    self.force_no_match = self.is_degenerate();
+    log::debug!("{:?}", self);
+    Ok(())
+  }
+
+  /// Whether subscribes/keywords/records/match_mode are all absent or empty
+  fn is_degenerate(&self) -> bool {
+    let subscribes_empty = match &self.subscribes {
+      None => true,
+      Some(s) => {
+        s.dids.as_ref().map_or(true, |d| d.is_empty())
+          && s.handles.as_ref().map_or(true, |h| h.is_empty())
+      }
     };
-    let Some(handles) = &follows.handles else {
-      return;
+    let keywords_empty = match &self.keywords {
+      None => true,
+      Some(k) => {
+        k.includes.as_ref().map_or(true, |i| i.is_empty())
+          && k.excludes.as_ref().map_or(true, |e| e.is_empty())
+      }
     };
-    let converted = handles
-      .iter()
-      .filter_map(|h| client.get_handle(h).ok())
-      .collect::<HashSet<_>>();
-    let dids = follows
-      .dids
-      .clone()
-      .map(|d| d.into_iter().collect::<HashSet<_>>())
-      .unwrap_or_default();
-    let dids = dids.union(&converted).cloned().collect::<Vec<_>>();
-    if dids.is_empty() {
-      follows.dids = None;
-    } else {
-      follows.dids = Some(dids);
+    let records_empty = match &self.records {
+      None => true,
+      Some(r) => {
+        r.collections.as_ref().map_or(true, |v| v.is_empty())
+          && r.langs.as_ref().map_or(true, |v| v.is_empty())
+          && r.has_media.is_none()
+          && r.is_reply.is_none()
+          && r.is_quote.is_none()
+          && r.mentions.as_ref().map_or(true, |v| v.is_empty())
+          && r.hashtags.as_ref().map_or(true, |v| v.is_empty())
+      }
+    };
+    subscribes_empty && keywords_empty && records_empty && self.match_mode.is_none()
+  }
+
+  /// Check this filter for misconfiguration: empty/degenerate conditions,
+  /// handles that failed to resolve during `init`, and (with
+  /// `keywords.regex` set) uncompilable patterns
+  pub fn validate(&self) -> Result<()> {
+    if let Some(keywords) = &self.keywords {
+      if keywords.regex.unwrap_or(false) {
+        Keywords::compile(&keywords.includes)
+          .and_then(|_| Keywords::compile(&keywords.excludes))
+          .map_err(|e| anyhow!("filter '{}': {e}", self.name))?;
+      }
     }
-    log::debug!("{:?}", self);
+    if self.force_no_match {
+      bail!(
+        "filter '{}' has no usable conditions left after init (empty subscribes/keywords/records/match_mode)",
+        self.name
+      );
+    }
+    if !self.unresolved_handles.is_empty() {
+      bail!(
+        "filter '{}' has handles that failed to resolve to a DID: {:?}",
+        self.name,
+        self.unresolved_handles
+      );
+    }
+    Ok(())
   }
 
   fn is_follows_match(&self, commit: &Commit) -> bool {
@@ -212,18 +680,91 @@ impl Filter {
     }
   }
 
+  /// Whether the filter's `records` condition matches; absent `records` is a wildcard
+  fn is_records_match(&self, commit: &Commit) -> bool {
+    match &self.records {
+      Some(r) => r.is_match(commit),
+      None => true,
+    }
+  }
+
+  /// The `Any` of whichever flat `subscribes`/`keywords`/`records` fields
+  /// are set, used when `match_mode` is not given so old `filters.yaml`
+  /// files keep parsing
+  fn default_condition(&self) -> Condition {
+    let mut any = Vec::new();
+    if self.subscribes.is_some() {
+      any.push(Condition::Subscribes);
+    }
+    if self.keywords.is_some() {
+      any.push(Condition::Keywords);
+    }
+    if self.records.is_some() {
+      any.push(Condition::Records);
+    }
+    Condition::Any(any)
+  }
+
+  /// Whether the event's repository/DID is on the owning [`Filters`]' blocklist
+  fn is_banned(&self, event: &Event) -> bool {
+    let Some(bans) = &self.bans else {
+      return false;
+    };
+    match &event.payload {
+      Payload::Commit(c) => bans.is_banned(&c.repo),
+      Payload::Handle(h) => bans.is_banned(&h.did),
+      _ => false,
+    }
+  }
+
   /// Returns whether or not the filter is matched
   pub fn is_match(&self, event: &Event) -> bool {
+    if self.force_no_match || self.is_banned(event) {
+      return false;
+    }
     match &event.payload {
-      Payload::Commit(c) => match self.is_follows_match(c) {
-        true => !self.is_keywords_excludes(c),
-        false => self.is_keywords_includes(c),
-      },
+      Payload::Commit(c) => {
+        if self.is_keywords_excludes(c) {
+          return false;
+        }
+        let condition = self
+          .match_mode
+          .clone()
+          .unwrap_or_else(|| self.default_condition());
+        condition.is_match(self, c)
+      }
       Payload::Handle(h) => self.is_handle_match(h),
       _ => true,
     }
   }
 
+  /// Run the configured actions for a matched event
+  ///
+  /// A failing action (webhook timeout, missing `RunCommand` binary,
+  /// unwritable `AppendJson` path, ...) is logged and does not prevent the
+  /// other configured actions from running.
+  pub fn run_actions(&self, event: &Event) -> Result<()> {
+    let Some(actions) = &self.actions else {
+      return Ok(());
+    };
+    let errors = actions
+      .iter()
+      .filter_map(|action| action.run(event).err())
+      .inspect(|e| log::error!("filter '{}' action failed: {e}", self.name))
+      .map(|e| e.to_string())
+      .collect::<Vec<_>>();
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      bail!(
+        "{} action(s) failed for filter '{}': {}",
+        errors.len(),
+        self.name,
+        errors.join("; ")
+      );
+    }
+  }
+
   /// Add a repository to subscribe to the Filter
   pub fn subscribe_repo<T: ToString>(&mut self, did: T) -> Result<()> {
     if self.subscribes.is_none() {
@@ -273,13 +814,47 @@ impl Filter {
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Filters {
   pub filters: Vec<Filter>,
+  pub bans: Option<Bans>,
 }
 
 impl Filters {
-  /// Initialize all included filters
-  pub fn init(&mut self, client: &mut Client) {
+  /// Initialize all included filters and resolve the ban list's handles,
+  /// refusing to start on a broken config
+  pub fn init(&mut self, client: &mut Client) -> Result<()> {
+    if let Some(bans) = self.bans.as_mut() {
+      bans.init(client);
+    }
+    for filter in self.filters.iter_mut() {
+      filter.init(client)?;
+    }
+    self.sync_bans();
+    self.validate()
+  }
+
+  /// Push the resolved ban list down into every filter, so `Filter::is_match`
+  /// (and thus `get_filters`) short-circuits to "no match" for banned
+  /// repositories even when called directly, without going through `dispatch`
+  fn sync_bans(&mut self) {
     for filter in self.filters.iter_mut() {
-      filter.init(client);
+      filter.bans = self.bans.clone();
+    }
+  }
+
+  /// Validate every filter, aggregating all errors into one
+  pub fn validate(&self) -> Result<()> {
+    let mut errors = self
+      .filters
+      .iter()
+      .filter_map(|f| f.validate().err())
+      .map(|e| e.to_string())
+      .collect::<Vec<_>>();
+    if let Some(bans) = &self.bans {
+      errors.extend(bans.validate().err().map(|e| e.to_string()));
+    }
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      bail!("invalid filters:\n{}", errors.join("\n"));
     }
   }
 
@@ -288,6 +863,71 @@ impl Filters {
     self.filters.clone()
   }
 
+  /// Returns whether the event's repository/DID is on the global blocklist
+  pub fn is_banned(&self, event: &Event) -> bool {
+    let Some(bans) = &self.bans else {
+      return false;
+    };
+    match &event.payload {
+      Payload::Commit(c) => bans.is_banned(&c.repo),
+      Payload::Handle(h) => bans.is_banned(&h.did),
+      _ => false,
+    }
+  }
+
+  /// Evaluate every filter against the event and run the actions of those
+  /// that match, short-circuiting banned repositories before any filter runs
+  ///
+  /// A filter whose actions fail is logged and does not prevent the other
+  /// filters from running theirs.
+  pub fn dispatch(&self, event: &Event) -> Result<()> {
+    if self.is_banned(event) {
+      return Ok(());
+    }
+    for filter in &self.filters {
+      if filter.is_match(event) {
+        if let Err(e) = filter.run_actions(event) {
+          log::error!("dispatch: {e}");
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Ban a DID, overriding all filters
+  pub fn ban_did<T: ToString>(&mut self, did: T) {
+    self.bans.get_or_insert_with(Bans::default).ban_did(did);
+    self.sync_bans();
+  }
+
+  /// Unban a DID
+  pub fn unban_did<T: ToString>(&mut self, did: T) -> Result<()> {
+    let Some(bans) = self.bans.as_mut() else {
+      bail!("no such did");
+    };
+    bans.unban_did(did)?;
+    self.sync_bans();
+    Ok(())
+  }
+
+  /// Add a handle to the ban list; it is resolved to a DID, and so takes
+  /// effect, only on the next `init`
+  pub fn ban_handle<T: ToString>(&mut self, handle: T) {
+    self.bans.get_or_insert_with(Bans::default).ban_handle(handle);
+    self.sync_bans();
+  }
+
+  /// Remove a handle from the ban list; a DID it already resolved to during
+  /// `init` stays banned until removed with `unban_did`
+  pub fn unban_handle<T: ToString>(&mut self, handle: T) -> Result<()> {
+    let Some(bans) = self.bans.as_mut() else {
+      bail!("no such handle");
+    };
+    bans.unban_handle(handle)?;
+    self.sync_bans();
+    Ok(())
+  }
+
   /// Add a repository to subscribe to the Filter given by name
   pub fn subscribe_repo<T1: ToString, T2: ToString>(&mut self, name: T1, did: T2) -> Result<()> {
     let Some(filter) = self.filters.iter_mut().find(|f| f.name == name.to_string()) else {